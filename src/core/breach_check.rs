@@ -0,0 +1,51 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs Ltd <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use gloo_net::http::Request;
+use sha1::{Digest, Sha1};
+
+const RANGE_API: &str = "https://api.pwnedpasswords.com/range/";
+
+pub enum BreachCheck {
+    Clean,
+    Breached { count: u64 },
+    // The range API could not be reached; callers should treat this as a
+    // non-blocking warning rather than a hard validation failure.
+    Unavailable,
+}
+
+// Checks whether `password` appears in the HaveIBeenPwned breach corpus using
+// k-anonymity: only the first five hex characters of the SHA-1 digest ever
+// leave the browser, so the plaintext (and the full hash) are never sent.
+pub async fn check(password: &str) -> BreachCheck {
+    let mut hasher = Sha1::new();
+    hasher.update(password.as_bytes());
+    let digest = hasher.finalize();
+    let hex = digest.iter().map(|b| format!("{b:02X}")).collect::<String>();
+    let (prefix, suffix) = hex.split_at(5);
+
+    let response = match Request::get(&format!("{RANGE_API}{prefix}")).send().await {
+        Ok(response) => response,
+        Err(_) => return BreachCheck::Unavailable,
+    };
+
+    let body = match response.text().await {
+        Ok(body) => body,
+        Err(_) => return BreachCheck::Unavailable,
+    };
+
+    for line in body.lines() {
+        if let Some((line_suffix, count)) = line.split_once(':') {
+            if line_suffix.eq_ignore_ascii_case(suffix) {
+                return BreachCheck::Breached {
+                    count: count.trim().parse().unwrap_or(0),
+                };
+            }
+        }
+    }
+
+    BreachCheck::Clean
+}
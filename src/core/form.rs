@@ -0,0 +1,250 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs Ltd <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use ahash::AHashMap;
+
+use crate::core::{
+    breach_check::{self, BreachCheck},
+    password_strength,
+    schema::{Schema, SchemaType, Validator},
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct IfThen {
+    pub if_: String,
+    pub then_: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Expression {
+    pub if_thens: Vec<IfThen>,
+    pub else_: String,
+}
+
+impl Expression {
+    pub fn is_empty(&self) -> bool {
+        self.if_thens.is_empty() && self.else_.is_empty()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum FormValue {
+    Value(String),
+    Array(Vec<String>),
+    Expression(Expression),
+}
+
+pub struct FormData {
+    pub schema: Schema,
+    pub is_update: bool,
+    pub values: AHashMap<String, FormValue>,
+}
+
+// Result of a validation pass: either the form is good to submit, it is
+// submittable but the operator should be warned (e.g. a best-effort check
+// could not be completed), or it must not be submitted at all.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationOutcome {
+    Valid,
+    Warning(String),
+    Invalid(String),
+}
+
+impl FormData {
+    pub fn value_as_str(&self, key: &str) -> Option<&str> {
+        match self.values.get(key) {
+            Some(FormValue::Value(value)) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn value_is_empty(&self, key: &str) -> bool {
+        match self.values.get(key) {
+            Some(FormValue::Value(value)) => value.is_empty(),
+            Some(FormValue::Array(values)) => values.is_empty(),
+            Some(FormValue::Expression(expr)) => expr.is_empty(),
+            None => true,
+        }
+    }
+
+    pub(crate) fn array_values(&self, key: &str) -> Vec<&str> {
+        match self.values.get(key) {
+            Some(FormValue::Array(values)) => values.iter().map(|v| v.as_str()).collect(),
+            Some(FormValue::Value(value)) if !value.is_empty() => vec![value.as_str()],
+            _ => Vec::new(),
+        }
+    }
+
+    // Synchronous validation pass: required fields, numeric bounds, password
+    // strength, and (for tenant records) the principal/domain invariant.
+    // `Validator::NotBreached` is enforced separately by `validate_breach`,
+    // since it requires an async network round-trip.
+    pub fn validate(&self) -> ValidationOutcome {
+        let mut weak_password_warning = None;
+
+        for field in self.schema.fields.values() {
+            for validator in &field.validators {
+                match validator {
+                    Validator::Required => {
+                        if self.value_is_empty(field.id) {
+                            return ValidationOutcome::Invalid(format!(
+                                "{} is required",
+                                field.label
+                            ));
+                        }
+                    }
+                    Validator::MinValue(min) => {
+                        if let Some(value) = self.value_as_str(field.id).and_then(|v| v.parse::<i64>().ok())
+                        {
+                            if value < min.0 {
+                                return ValidationOutcome::Invalid(format!(
+                                    "{} must be at least {}",
+                                    field.label, min.0
+                                ));
+                            }
+                        }
+                    }
+                    Validator::MaxValue(max) => {
+                        if let Some(value) = self.value_as_str(field.id).and_then(|v| v.parse::<i64>().ok())
+                        {
+                            if value > max.0 {
+                                return ValidationOutcome::Invalid(format!(
+                                    "{} must be at most {}",
+                                    field.label, max.0
+                                ));
+                            }
+                        }
+                    }
+                    Validator::IsId => {
+                        if let Some(value) = self.value_as_str(field.id) {
+                            if !value.is_empty()
+                                && !value
+                                    .chars()
+                                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                            {
+                                return ValidationOutcome::Invalid(format!(
+                                    "{} may only contain letters, digits, '-' and '_'",
+                                    field.label
+                                ));
+                            }
+                        }
+                    }
+                    Validator::PasswordStrength { min_bits } => {
+                        if let Some(value) = self.value_as_str(field.id) {
+                            if !value.is_empty() {
+                                let bits = password_strength::estimate_bits(value);
+                                if bits < *min_bits as f64 {
+                                    return ValidationOutcome::Invalid(format!(
+                                        "{} is too weak (~{bits:.0} bits of entropy, {min_bits} required)",
+                                        field.label
+                                    ));
+                                }
+
+                                let label = password_strength::strength_label(bits);
+                                if weak_password_warning.is_none() && bits < 70.0 {
+                                    weak_password_warning = Some(format!(
+                                        "{} passes the minimum strength requirement, but its estimated \
+                                         strength is only '{label}' (~{bits:.0} bits); consider a longer \
+                                         or more varied secret",
+                                        field.label
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                    Validator::NotBreached => {}
+                }
+            }
+        }
+
+        if let SchemaType::Record { prefix } = &self.schema.typ {
+            if *prefix == "tenant" {
+                if let Err(err) = self.validate_tenant_domains() {
+                    return ValidationOutcome::Invalid(err);
+                }
+            }
+        }
+
+        match weak_password_warning {
+            Some(warning) => ValidationOutcome::Warning(warning),
+            None => ValidationOutcome::Valid,
+        }
+    }
+
+    // Checks every field carrying `Validator::NotBreached` against the HIBP
+    // range API, gated on the `authentication.secret-breach-check` toggle. A
+    // confirmed breach is a hard failure; a network failure degrades to a
+    // warning so a flaky connection never blocks an admin from saving
+    // settings.
+    pub async fn validate_breach(&self) -> ValidationOutcome {
+        if self.value_as_str("authentication.secret-breach-check") != Some("true") {
+            return ValidationOutcome::Valid;
+        }
+
+        let mut unavailable_warning = None;
+
+        for field in self.schema.fields.values() {
+            if !field.validators.contains(&Validator::NotBreached) {
+                continue;
+            }
+
+            let Some(value) = self.value_as_str(field.id) else {
+                continue;
+            };
+            if value.is_empty() {
+                continue;
+            }
+
+            match breach_check::check(value).await {
+                BreachCheck::Breached { count } => {
+                    return ValidationOutcome::Invalid(format!(
+                        "{} has appeared in {count} known data breaches",
+                        field.label
+                    ));
+                }
+                BreachCheck::Unavailable => {
+                    if unavailable_warning.is_none() {
+                        unavailable_warning = Some(format!(
+                            "Could not reach the breach-check service for {}; continuing without this check",
+                            field.label
+                        ));
+                    }
+                }
+                BreachCheck::Clean => {}
+            }
+        }
+
+        match unavailable_warning {
+            Some(warning) => ValidationOutcome::Warning(warning),
+            None => ValidationOutcome::Valid,
+        }
+    }
+
+    // Ensures every principal name listed under a tenant record belongs to
+    // one of the domains owned by that tenant, mirroring the server-side
+    // invariant that rejects tenant principals whose name does not include a
+    // valid tenant domain.
+    pub(crate) fn validate_tenant_domains(&self) -> Result<(), String> {
+        let domains = self
+            .array_values("domains")
+            .into_iter()
+            .map(|value| value.to_lowercase())
+            .collect::<Vec<_>>();
+
+        for principal in self.array_values("members") {
+            if !domains
+                .iter()
+                .any(|domain| principal.to_lowercase().ends_with(&format!("@{domain}")))
+            {
+                return Err(format!(
+                    "Principal '{principal}' does not belong to any domain owned by this tenant"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
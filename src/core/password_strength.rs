@@ -0,0 +1,366 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs Ltd <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+// Common passwords rejected outright by `Validator::PasswordStrength`,
+// regardless of their character-class entropy (e.g. "Password1" scores well
+// on pool size alone but is still one of the first guesses an attacker
+// tries). This list is deliberately not exhaustive — full breach corpora run
+// into the millions of entries and would bloat the webadmin bundle — but it
+// covers the passwords most commonly seen across leaked credential dumps.
+const COMMON_PASSWORDS: &[&str] = &[
+    "123456",
+    "123456789",
+    "12345678",
+    "12345",
+    "1234567",
+    "1234567890",
+    "1234",
+    "111111",
+    "000000",
+    "123123",
+    "1234560",
+    "123321",
+    "654321",
+    "666666",
+    "7777777",
+    "121212",
+    "112233",
+    "qwerty",
+    "qwerty123",
+    "qwertyuiop",
+    "qwerty1",
+    "1q2w3e",
+    "1q2w3e4r",
+    "1qaz2wsx",
+    "asdf",
+    "asdfgh",
+    "asdfghjkl",
+    "zxcvbn",
+    "zxcvbnm",
+    "password",
+    "password1",
+    "password123",
+    "passw0rd",
+    "p@ssw0rd",
+    "pass1234",
+    "admin",
+    "administrator",
+    "admin123",
+    "root",
+    "toor",
+    "letmein",
+    "letmein1",
+    "welcome",
+    "welcome1",
+    "login",
+    "loginpassword",
+    "changeme",
+    "secret",
+    "secret1",
+    "iloveyou",
+    "iloveyou1",
+    "princess",
+    "princess1",
+    "sunshine",
+    "sunshine1",
+    "dragon",
+    "dragon1",
+    "monkey",
+    "monkey1",
+    "master",
+    "master1",
+    "shadow",
+    "football",
+    "baseball",
+    "basketball",
+    "soccer",
+    "hockey",
+    "golfer",
+    "golf",
+    "batman",
+    "superman",
+    "spiderman",
+    "starwars",
+    "pokemon",
+    "mario",
+    "zelda",
+    "michael",
+    "jennifer",
+    "jessica",
+    "ashley",
+    "amanda",
+    "daniel",
+    "matthew",
+    "andrew",
+    "joshua",
+    "christopher",
+    "nicholas",
+    "anthony",
+    "william",
+    "thomas",
+    "charlie",
+    "george",
+    "jordan",
+    "jordan23",
+    "lebron",
+    "kobe24",
+    "tigger",
+    "whatever",
+    "freedom",
+    "trustno1",
+    "hunter",
+    "hunter2",
+    "killer",
+    "ninja",
+    "samsung",
+    "apple",
+    "google",
+    "facebook",
+    "instagram",
+    "twitter",
+    "youtube",
+    "summer",
+    "winter",
+    "spring",
+    "autumn",
+    "august",
+    "january",
+    "december",
+    "flower",
+    "butterfly",
+    "rainbow",
+    "diamond",
+    "silver",
+    "golden",
+    "orange",
+    "purple",
+    "yellow",
+    "redsox",
+    "yankees",
+    "cowboys",
+    "lakers",
+    "eagles",
+    "chicken",
+    "dolphin",
+    "tiger",
+    "lion",
+    "panther",
+    "wolf",
+    "phoenix",
+    "cheese",
+    "pizza",
+    "coffee",
+    "chocolate",
+    "banana",
+    "apple123",
+    "peach",
+    "abc123",
+    "abc12345",
+    "a1b2c3",
+    "aaaaaa",
+    "bbbbbb",
+    "cccccc",
+    "121314",
+    "232323",
+    "qazwsx",
+    "qazwsx123",
+    "xsw2",
+    "zaq12wsx",
+    "1qazxsw2",
+    "q1w2e3r4",
+    "p0o9i8u7",
+    "asd123",
+    "asdzxc",
+    "iloveyou2",
+    "loveyou",
+    "love123",
+    "baby123",
+    "123abc",
+    "abcd1234",
+    "test123",
+    "testing",
+    "test1234",
+    "temp123",
+    "temppass",
+    "newpass",
+    "newpass123",
+    "changeme123",
+    "default",
+    "defaultpass",
+    "guest",
+    "guest123",
+    "user123",
+    "demo123",
+    "sample123",
+    "backup123",
+    "system123",
+    "server123",
+    "network123",
+    "internet",
+    "computer",
+    "keyboard",
+    "mouse123",
+    "monitor123",
+    "camera123",
+    "mobile123",
+    "android123",
+    "iphone123",
+    "windows123",
+    "linux123",
+    "ubuntu123",
+    "debian123",
+    "mysql123",
+    "oracle123",
+    "database1",
+    "matrix",
+    "matrix1",
+    "neo123",
+    "trinity1",
+    "morpheus",
+    "agent007",
+    "james007",
+    "007james",
+    "bond007",
+    "skyfall",
+    "goldeneye",
+    "thunder",
+    "lightning",
+    "storm123",
+    "hurricane",
+    "tornado1",
+    "volcano1",
+    "mountain",
+    "ocean123",
+    "river123",
+    "forest1",
+    "garden123",
+    "flower1",
+    "rose1234",
+    "lily1234",
+    "daisy123",
+    "violet1",
+    "jasmine1",
+    "cookie123",
+    "candy123",
+    "sugar123",
+    "honey123",
+    "sweetie1",
+    "darling1",
+    "angel123",
+    "beauty123",
+    "pretty123",
+    "lovely123",
+    "charming",
+    "graceful",
+    "elegant1",
+    "stylish1",
+    "fashion1",
+    "trendy123",
+    "classic1",
+    "vintage1",
+    "modern12",
+    "future12",
+    "pastfuture",
+];
+
+// Classifies an entropy estimate into a coarse strength label. It is used in
+// the weak-password warning of `FormData::validate`; no input component
+// displays it while the secret is being typed.
+pub fn strength_label(bits: f64) -> &'static str {
+    if bits <= 0.0 {
+        "Very weak"
+    } else if bits < 28.0 {
+        "Weak"
+    } else if bits < 50.0 {
+        "Fair"
+    } else if bits < 70.0 {
+        "Strong"
+    } else {
+        "Very strong"
+    }
+}
+
+// Returns an estimate of the Shannon entropy, in bits, of `password`.
+//
+// The estimate uses the size of the smallest character-class pool that covers
+// every character in the password, penalizing passwords made of a single
+// repeated character and sequential runs (e.g. "abcd", "1234") that a naive
+// pool-size calculation would otherwise overvalue.
+pub fn estimate_bits(password: &str) -> f64 {
+    if password.is_empty() {
+        return 0.0;
+    }
+
+    if COMMON_PASSWORDS
+        .iter()
+        .any(|common| common.eq_ignore_ascii_case(password))
+    {
+        return 0.0;
+    }
+
+    let mut pool_size = 0u32;
+    let (mut has_lower, mut has_upper, mut has_digit, mut has_symbol) = (false, false, false, false);
+    for c in password.chars() {
+        if c.is_ascii_lowercase() {
+            has_lower = true;
+        } else if c.is_ascii_uppercase() {
+            has_upper = true;
+        } else if c.is_ascii_digit() {
+            has_digit = true;
+        } else {
+            has_symbol = true;
+        }
+    }
+    if has_lower {
+        pool_size += 26;
+    }
+    if has_upper {
+        pool_size += 26;
+    }
+    if has_digit {
+        pool_size += 10;
+    }
+    if has_symbol {
+        pool_size += 33;
+    }
+    pool_size = pool_size.max(1);
+
+    let len = password.chars().count() as f64;
+    let mut bits = len * (pool_size as f64).log2();
+
+    if is_all_same_char(password) {
+        bits *= 0.1;
+    } else if has_sequential_run(password, 4) {
+        bits *= 0.5;
+    }
+
+    bits.max(0.0)
+}
+
+fn is_all_same_char(password: &str) -> bool {
+    let mut chars = password.chars();
+    match chars.next() {
+        Some(first) => chars.all(|c| c == first),
+        None => false,
+    }
+}
+
+fn has_sequential_run(password: &str, run_len: usize) -> bool {
+    let chars: Vec<char> = password.chars().collect();
+    if chars.len() < run_len {
+        return false;
+    }
+
+    chars.windows(run_len).any(|window| {
+        let ascending = window
+            .windows(2)
+            .all(|pair| pair[1] as i32 - pair[0] as i32 == 1);
+        let descending = window
+            .windows(2)
+            .all(|pair| pair[0] as i32 - pair[1] as i32 == 1);
+        ascending || descending
+    })
+}
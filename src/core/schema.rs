@@ -0,0 +1,314 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs Ltd <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use ahash::AHashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Number(pub i64);
+
+impl From<i32> for Number {
+    fn from(value: i32) -> Self {
+        Number(value as i64)
+    }
+}
+
+impl From<u32> for Number {
+    fn from(value: u32) -> Self {
+        Number(value as i64)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DynamicFilter(pub Vec<(&'static str, &'static str)>);
+
+#[derive(Debug, Clone)]
+pub enum Source {
+    Static(&'static [(&'static str, &'static str)]),
+    Dynamic {
+        schema: &'static str,
+        field: &'static str,
+        filter: DynamicFilter,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum Type {
+    Input,
+    Secret,
+    Select { source: Source, multi: bool },
+    Array,
+    Boolean,
+    Duration,
+    Rate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transformer {
+    Trim,
+    HashSecret,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Validator {
+    Required,
+    MinValue(Number),
+    MaxValue(Number),
+    IsId,
+    // Rejects secrets whose estimated entropy falls below `min_bits`.
+    PasswordStrength { min_bits: u32 },
+    // Rejects secrets found in the HaveIBeenPwned breach corpus; enforced
+    // asynchronously by `FormData::validate_breach` since it requires a
+    // network round-trip.
+    NotBreached,
+}
+
+#[derive(Debug, Clone)]
+pub enum SchemaType {
+    Record { prefix: &'static str },
+    Entry { prefix: &'static str },
+    List,
+}
+
+impl Default for SchemaType {
+    fn default() -> Self {
+        SchemaType::List
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Field {
+    pub id: &'static str,
+    pub label: &'static str,
+    pub help: &'static str,
+    pub typ_: Type,
+    pub default: Option<&'static str>,
+    pub transformers: Vec<Transformer>,
+    pub validators: Vec<Validator>,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::Input
+    }
+}
+
+impl Field {
+    pub fn is_multivalue(&self) -> bool {
+        matches!(
+            self.typ_,
+            Type::Array | Type::Select { multi: true, .. }
+        )
+    }
+
+    pub fn has_transformer(&self, transformer: Transformer) -> bool {
+        self.transformers.contains(&transformer)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FormSection {
+    pub title: &'static str,
+    pub fields: Vec<&'static str>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    pub id: &'static str,
+    pub typ: SchemaType,
+    pub fields: AHashMap<&'static str, Field>,
+    pub form_sections: Vec<FormSection>,
+    pub list_title: &'static str,
+    pub list_subtitle: &'static str,
+    pub list_fields: Vec<&'static str>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Schemas {
+    pub schemas: AHashMap<&'static str, Schema>,
+}
+
+pub struct Builder<T, S> {
+    schemas: T,
+    state: S,
+}
+
+#[derive(Default)]
+pub struct SchemaBuilder {
+    schema: Schema,
+}
+
+pub struct FieldBuilder {
+    schema: SchemaBuilder,
+    field: Field,
+}
+
+pub struct FormSectionBuilder {
+    schema: SchemaBuilder,
+    section: FormSection,
+}
+
+impl Builder<Schemas, ()> {
+    pub fn new() -> Self {
+        Builder {
+            schemas: Schemas::default(),
+            state: (),
+        }
+    }
+
+    pub fn new_schema(self, id: &'static str) -> Builder<Schemas, SchemaBuilder> {
+        Builder {
+            schemas: self.schemas,
+            state: SchemaBuilder {
+                schema: Schema {
+                    id,
+                    ..Default::default()
+                },
+            },
+        }
+    }
+
+    pub fn finish(self) -> Schemas {
+        self.schemas
+    }
+}
+
+impl Default for Builder<Schemas, ()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Builder<Schemas, SchemaBuilder> {
+    pub fn prefix(mut self, prefix: &'static str) -> Self {
+        self.state.schema.typ = SchemaType::Record { prefix };
+        self
+    }
+
+    pub fn new_id_field(self) -> Builder<Schemas, FieldBuilder> {
+        Builder {
+            schemas: self.schemas,
+            state: FieldBuilder {
+                schema: self.state,
+                field: Field {
+                    id: "_id",
+                    ..Default::default()
+                },
+            },
+        }
+    }
+
+    pub fn new_field(self, id: &'static str) -> Builder<Schemas, FieldBuilder> {
+        Builder {
+            schemas: self.schemas,
+            state: FieldBuilder {
+                schema: self.state,
+                field: Field {
+                    id,
+                    ..Default::default()
+                },
+            },
+        }
+    }
+
+    pub fn new_form_section(self) -> Builder<Schemas, FormSectionBuilder> {
+        Builder {
+            schemas: self.schemas,
+            state: FormSectionBuilder {
+                schema: self.state,
+                section: FormSection::default(),
+            },
+        }
+    }
+
+    pub fn list_title(mut self, title: &'static str) -> Self {
+        self.state.schema.list_title = title;
+        self
+    }
+
+    pub fn list_subtitle(mut self, subtitle: &'static str) -> Self {
+        self.state.schema.list_subtitle = subtitle;
+        self
+    }
+
+    pub fn list_fields(
+        mut self,
+        fields: impl IntoIterator<Item = &'static str>,
+    ) -> Self {
+        self.state.schema.list_fields = fields.into_iter().collect();
+        self
+    }
+
+    pub fn build(mut self) -> Builder<Schemas, ()> {
+        let schema = self.state.schema;
+        self.schemas.schemas.insert(schema.id, schema);
+        Builder {
+            schemas: self.schemas,
+            state: (),
+        }
+    }
+}
+
+impl Builder<Schemas, FieldBuilder> {
+    pub fn label(mut self, label: &'static str) -> Self {
+        self.state.field.label = label;
+        self
+    }
+
+    pub fn help(mut self, help: &'static str) -> Self {
+        self.state.field.help = help;
+        self
+    }
+
+    pub fn typ(mut self, typ: Type) -> Self {
+        self.state.field.typ_ = typ;
+        self
+    }
+
+    pub fn default(mut self, default: &'static str) -> Self {
+        self.state.field.default = Some(default);
+        self
+    }
+
+    pub fn input_check(
+        mut self,
+        transformers: impl IntoIterator<Item = Transformer>,
+        validators: impl IntoIterator<Item = Validator>,
+    ) -> Self {
+        self.state.field.transformers = transformers.into_iter().collect();
+        self.state.field.validators = validators.into_iter().collect();
+        self
+    }
+
+    pub fn build(self) -> Builder<Schemas, SchemaBuilder> {
+        let mut schema = self.state.schema;
+        schema.schema.fields.insert(self.state.field.id, self.state.field);
+        Builder {
+            schemas: self.schemas,
+            state: schema,
+        }
+    }
+}
+
+impl Builder<Schemas, FormSectionBuilder> {
+    pub fn title(mut self, title: &'static str) -> Self {
+        self.state.section.title = title;
+        self
+    }
+
+    pub fn fields(mut self, fields: impl IntoIterator<Item = &'static str>) -> Self {
+        self.state.section.fields.extend(fields);
+        self
+    }
+
+    pub fn build(self) -> Builder<Schemas, SchemaBuilder> {
+        let mut schema = self.state.schema;
+        schema.schema.form_sections.push(self.state.section);
+        Builder {
+            schemas: self.schemas,
+            state: schema,
+        }
+    }
+}
@@ -4,13 +4,16 @@ pub mod schema;
 
 use crate::{
     components::{
-        icon::{IconCircleStack, IconServerStack, IconShieldCheck, IconUserGroup},
+        icon::{
+            IconBuildingOffice2, IconCircleStack, IconServerStack, IconShieldCheck, IconUserGroup,
+        },
         layout::{LayoutBuilder, MenuItem},
     },
     core::{
-        form::{FormData, FormValue},
+        form::{FormData, FormValue, ValidationOutcome},
         schema::*,
     },
+    pages::config::schema::secret_hash::{hash_secret, is_hashed, scheme_name_from_hash, HashScheme},
 };
 use ahash::AHashMap;
 use leptos::view;
@@ -35,7 +38,20 @@ pub enum UpdateSettings {
 }
 
 impl FormData {
-    pub fn build_update(&self) -> Vec<UpdateSettings> {
+    // Serializes the form into the updates the settings store expects.
+    // `FormData::validate`/`validate_breach` should still be called first so
+    // the operator gets feedback on every violation at once, but
+    // `build_update` is public and re-checks the invariants it depends on
+    // (the tenant principal/domain assertion, successful secret hashing)
+    // itself — a caller that skips straight to `build_update` without going
+    // through `submit` still cannot write an inconsistent update.
+    pub fn build_update(&self) -> Result<Vec<UpdateSettings>, String> {
+        if let SchemaType::Record { prefix } = &self.schema.typ {
+            if *prefix == "tenant" {
+                self.validate_tenant_domains()?;
+            }
+        }
+
         let mut updates = Vec::new();
         let mut insert_prefix = None;
         let mut assert_empty = false;
@@ -61,7 +77,7 @@ impl FormData {
                         self.value_as_str("_value").unwrap_or_default().to_string(),
                     )],
                 });
-                return updates;
+                return Ok(updates);
             }
             SchemaType::List => {
                 if self.is_update {
@@ -92,7 +108,25 @@ impl FormData {
 
             match value {
                 FormValue::Value(value) if !value.is_empty() => {
-                    key_values.push((key.to_string(), value.to_string()));
+                    let value = if self
+                        .schema
+                        .fields
+                        .get(key.as_str())
+                        .is_some_and(|field| field.has_transformer(Transformer::HashSecret))
+                    {
+                        let scheme = self
+                            .value_as_str("authentication.secret-hash.scheme")
+                            .map(HashScheme::parse)
+                            .unwrap_or(HashScheme::Plain);
+                        if is_hashed(value) {
+                            value.to_string()
+                        } else {
+                            hash_secret(scheme, value)?
+                        }
+                    } else {
+                        value.to_string()
+                    };
+                    key_values.push((key.to_string(), value));
                 }
                 FormValue::Array(values) if !values.is_empty() => {
                     let total_values = values.len();
@@ -142,7 +176,26 @@ impl FormData {
             });
         }
 
-        updates
+        Ok(updates)
+    }
+
+    // The actual submission-time validation path: runs the synchronous
+    // validators, then the breach check (gated on
+    // `authentication.secret-breach-check`, degrading to a non-blocking
+    // warning on network failure), and only serializes the form once both
+    // have passed. `build_update` re-checks the invariants it depends on, so
+    // this mainly buys the operator a single upfront error instead of one
+    // fix-and-resubmit cycle per violation.
+    pub async fn submit(&self) -> Result<Vec<UpdateSettings>, String> {
+        if let ValidationOutcome::Invalid(err) = self.validate() {
+            return Err(err);
+        }
+
+        if let ValidationOutcome::Invalid(err) = self.validate_breach().await {
+            return Err(err);
+        }
+
+        self.build_update()
     }
 }
 
@@ -195,6 +248,14 @@ impl SettingsValues for Settings {
                 .map(|(_, v)| v.to_string())
                 .unwrap_or_default(),
 
+            Type::Secret => {
+                let value = self.get(field.id).map(|s| s.as_str()).unwrap_or_default();
+                match scheme_name_from_hash(value) {
+                    Some(scheme) => format!("(hashed: {scheme})"),
+                    None => value.to_string(),
+                }
+            }
+
             _ => self
                 .get(field.id)
                 .map(|s| s.as_str())
@@ -234,6 +295,15 @@ impl LayoutBuilder {
             .create("System")
             .route("/system/edit")
             .insert()
+            // Authentication
+            .create("Authentication")
+            .create("Settings")
+            .route("/authentication/edit")
+            .insert()
+            .create("Multi-Factor Auth")
+            .route("/mfa/edit")
+            .insert()
+            .insert()
             .insert()
             // Stores
             .create("Stores")
@@ -245,6 +315,11 @@ impl LayoutBuilder {
             .icon(view! { <IconUserGroup/> })
             .route("/directory")
             .insert()
+            // Tenants
+            .create("Tenants")
+            .icon(view! { <IconBuildingOffice2/> })
+            .route("/tenant")
+            .insert()
             // SPAM Filter
             .create("SPAM Filter")
             .icon(view! { <IconShieldCheck/> })
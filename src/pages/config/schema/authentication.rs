@@ -38,6 +38,34 @@ impl Builder<Schemas, ()> {
             .typ(Type::Rate)
             .default("10/1m")
             .build()
+            .new_field("authentication.secret-hash.scheme")
+            .label("Hashing scheme")
+            .help(concat!(
+                "Scheme used to hash the fallback admin and master secrets before they ",
+                "are written to the settings store"
+            ))
+            .typ(Type::Select {
+                source: Source::Static(&[
+                    ("plain", "Plain text"),
+                    ("bcrypt", "bcrypt"),
+                    ("argon2id", "argon2id"),
+                    ("sha512-crypt", "sha512-crypt"),
+                    ("pbkdf2", "PBKDF2"),
+                ]),
+                multi: false,
+            })
+            .default("argon2id")
+            .build()
+            .new_field("authentication.secret-breach-check")
+            .label("Check for breached passwords")
+            .help(concat!(
+                "Reject the fallback admin and master secrets if they appear in known ",
+                "password breach corpora, using a k-anonymity lookup that never exposes ",
+                "the plaintext secret. Directory-managed secrets are not checked"
+            ))
+            .typ(Type::Boolean)
+            .default("false")
+            .build()
             // Fallback admin
             .new_field("authentication.fallback-admin.user")
             .label("Username")
@@ -55,7 +83,13 @@ impl Builder<Schemas, ()> {
                 "in case the directory becomes unavailable"
             ))
             .typ(Type::Secret)
-            .input_check([Transformer::Trim], [])
+            .input_check(
+                [Transformer::Trim, Transformer::HashSecret],
+                [
+                    Validator::PasswordStrength { min_bits: 50 },
+                    Validator::NotBreached,
+                ],
+            )
             .build()
             // Master user
             .new_field("authentication.master.user")
@@ -74,7 +108,13 @@ impl Builder<Schemas, ()> {
                 "The master user secret to access any user account ",
             ))
             .typ(Type::Secret)
-            .input_check([Transformer::Trim], [])
+            .input_check(
+                [Transformer::Trim, Transformer::HashSecret],
+                [
+                    Validator::PasswordStrength { min_bits: 50 },
+                    Validator::NotBreached,
+                ],
+            )
             .build()
             .new_form_section()
             .title("Authentication")
@@ -93,7 +133,56 @@ impl Builder<Schemas, ()> {
             .build()
             .new_form_section()
             .title("Security")
-            .fields(["authentication.rate-limit", "authentication.fail2ban"])
+            .fields([
+                "authentication.rate-limit",
+                "authentication.fail2ban",
+                "authentication.secret-hash.scheme",
+                "authentication.secret-breach-check",
+            ])
+            .build()
+            // Protected actions
+            .new_field("authentication.protected-actions.enable")
+            .label("Enable")
+            .help(concat!(
+                "Require a one-time code confirmation before sensitive admin operations, ",
+                "such as editing the fallback admin, rotating the OAuth key, deleting ",
+                "directories or clearing stores"
+            ))
+            .typ(Type::Boolean)
+            .default("false")
+            .build()
+            .new_field("authentication.protected-actions.methods")
+            .label("Methods")
+            .help("Methods available to confirm a protected action")
+            .typ(Type::Select {
+                source: Source::Static(&[("email", "Email"), ("totp", "TOTP")]),
+                multi: true,
+            })
+            .default("email")
+            .build()
+            .new_field("authentication.protected-actions.otp-expiry")
+            .label("Code expiry")
+            .help("Amount of time a one-time confirmation code remains valid")
+            .typ(Type::Duration)
+            .default("10m")
+            .input_check([], [Validator::Required])
+            .build()
+            .new_field("authentication.protected-actions.operations")
+            .label("Protected operations")
+            .help(concat!(
+                "Operation categories that require step-up confirmation before they ",
+                "can be performed"
+            ))
+            .typ(Type::Array)
+            .build()
+            .new_form_section()
+            .title("Protected Actions")
+            .fields([
+                "authentication.protected-actions.enable",
+                "authentication.protected-actions.methods",
+                "authentication.protected-actions.otp-expiry",
+                "authentication.protected-actions.operations",
+            ])
             .build()
             .build()
             // OAuth
@@ -0,0 +1,94 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs Ltd <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::*;
+
+impl Builder<Schemas, ()> {
+    pub fn build_mfa(self) -> Self {
+        self.new_schema("mfa")
+            .new_field("auth.mfa.required")
+            .label("Enforcement")
+            .help("Which accounts are required to complete a second authentication factor")
+            .typ(Type::Select {
+                source: Source::Static(&[
+                    ("off", "Off"),
+                    ("admins-only", "Admins only"),
+                    ("all-users", "All users"),
+                ]),
+                multi: false,
+            })
+            .default("off")
+            .input_check([], [Validator::Required])
+            .build()
+            .new_field("auth.mfa.methods")
+            .label("Allowed methods")
+            .help("Second factor methods accounts are allowed to enroll")
+            .typ(Type::Select {
+                source: Source::Static(&[
+                    ("totp", "TOTP"),
+                    ("webauthn", "WebAuthn"),
+                    ("email-otp", "Email one-time code"),
+                ]),
+                multi: true,
+            })
+            .default("totp")
+            .build()
+            .new_field("auth.mfa.totp.issuer")
+            .label("TOTP issuer")
+            .help("Issuer name shown in authenticator apps when enrolling a TOTP device")
+            .typ(Type::Input)
+            .input_check([Transformer::Trim], [Validator::Required])
+            .default("Stalwart Mail Server")
+            .build()
+            .new_field("auth.mfa.totp.digits")
+            .label("TOTP digits")
+            .help("Number of digits in a generated TOTP code")
+            .typ(Type::Input)
+            .default("6")
+            .input_check(
+                [],
+                [
+                    Validator::Required,
+                    Validator::MinValue(6.into()),
+                    Validator::MaxValue(8.into()),
+                ],
+            )
+            .build()
+            .new_field("auth.mfa.totp.window")
+            .label("TOTP window")
+            .help("Number of time steps before and after the current one that are accepted")
+            .typ(Type::Input)
+            .default("1")
+            .input_check([], [Validator::MinValue(0.into())])
+            .build()
+            .new_field("auth.mfa.app-passwords.enable")
+            .label("Enable application passwords")
+            .help(concat!(
+                "Allow users to generate per-client application passwords that bypass ",
+                "MFA for legacy IMAP and SMTP clients that cannot prompt for a second factor"
+            ))
+            .typ(Type::Boolean)
+            .default("false")
+            .build()
+            .new_form_section()
+            .title("Enforcement")
+            .fields(["auth.mfa.required", "auth.mfa.methods"])
+            .build()
+            .new_form_section()
+            .title("TOTP")
+            .fields([
+                "auth.mfa.totp.issuer",
+                "auth.mfa.totp.digits",
+                "auth.mfa.totp.window",
+            ])
+            .build()
+            .new_form_section()
+            .title("Application Passwords")
+            .fields(["auth.mfa.app-passwords.enable"])
+            .build()
+            .build()
+    }
+}
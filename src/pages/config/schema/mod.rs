@@ -0,0 +1,21 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs Ltd <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+pub mod authentication;
+pub mod mfa;
+pub mod secret_hash;
+pub mod tenants;
+
+pub(crate) use crate::core::schema::*;
+
+// Builds the full settings schema registry served to the webadmin UI.
+pub fn build_schemas() -> Schemas {
+    Builder::<Schemas, ()>::new()
+        .build_authentication()
+        .build_tenants()
+        .build_mfa()
+        .finish()
+}
@@ -0,0 +1,106 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs Ltd <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use pwhash::{bcrypt, sha512_crypt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashScheme {
+    Plain,
+    Bcrypt,
+    Argon2id,
+    Sha512Crypt,
+    Pbkdf2,
+}
+
+impl HashScheme {
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "bcrypt" => HashScheme::Bcrypt,
+            "argon2id" => HashScheme::Argon2id,
+            "sha512-crypt" => HashScheme::Sha512Crypt,
+            "pbkdf2" => HashScheme::Pbkdf2,
+            _ => HashScheme::Plain,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            HashScheme::Plain => "plain",
+            HashScheme::Bcrypt => "bcrypt",
+            HashScheme::Argon2id => "argon2id",
+            HashScheme::Sha512Crypt => "sha512-crypt",
+            HashScheme::Pbkdf2 => "pbkdf2",
+        }
+    }
+}
+
+// Encodes `secret` using the configured scheme, producing the same crypt-style
+// strings (e.g. `$2b$...`, `$argon2id$...`) that the directory backend
+// understands when verifying a stored secret. A hashing failure is returned
+// to the caller rather than rescued with the plaintext secret — silently
+// falling back to plaintext is exactly the cleartext-credential outcome
+// hashing exists to prevent.
+pub fn hash_secret(scheme: HashScheme, secret: &str) -> Result<String, String> {
+    match scheme {
+        HashScheme::Plain => Ok(secret.to_string()),
+        HashScheme::Bcrypt => {
+            bcrypt::hash(secret).map_err(|err| format!("Failed to hash secret: {err}"))
+        }
+        HashScheme::Argon2id => argon2::hash_encoded(
+            secret.as_bytes(),
+            argon2_salt().as_bytes(),
+            &argon2::Config {
+                variant: argon2::Variant::Argon2id,
+                ..Default::default()
+            },
+        )
+        .map_err(|err| format!("Failed to hash secret: {err}")),
+        HashScheme::Sha512Crypt => {
+            sha512_crypt::hash(secret).map_err(|err| format!("Failed to hash secret: {err}"))
+        }
+        HashScheme::Pbkdf2 => pbkdf2::pbkdf2_simple(secret, 100_000)
+            .map_err(|err| format!("Failed to hash secret: {err}")),
+    }
+}
+
+// Returns true if `value` is already one of our recognized crypt-style
+// encodings rather than a plaintext secret, so the UI can avoid double-hashing
+// or displaying it in the clear. A bare leading `$` is not sufficient — a
+// legitimate plaintext secret like `$ecretPass` would otherwise be stored
+// verbatim and mislabeled as hashed.
+pub fn is_hashed(value: &str) -> bool {
+    scheme_name_from_hash(value).is_some()
+}
+
+// Identifies the scheme behind an already-encoded hash from its crypt-style
+// prefix, for display purposes only.
+pub fn scheme_name_from_hash(value: &str) -> Option<&'static str> {
+    if value.starts_with("$argon2id$") {
+        Some("argon2id")
+    } else if value.starts_with("$2a$") || value.starts_with("$2b$") || value.starts_with("$2y$") {
+        Some("bcrypt")
+    } else if value.starts_with("$6$") {
+        Some("sha512-crypt")
+    } else if value.starts_with("$rpbkdf2$") {
+        Some("pbkdf2")
+    } else {
+        None
+    }
+}
+
+// Generates a random salt for argon2 hashing. `getrandom` is called directly
+// (rather than through `uuid`, which relies on it transparently) so a missing
+// randomness backend fails loudly instead of silently producing a
+// predictable salt — under `wasm32-unknown-unknown` this requires the `js`
+// feature of the `getrandom` crate to be enabled, since the wasm target has
+// no OS RNG of its own.
+fn argon2_salt() -> String {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes).expect(
+        "no randomness backend available; enable the `js` feature of the `getrandom` crate for wasm32 targets",
+    );
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -0,0 +1,71 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs Ltd <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::*;
+
+impl Builder<Schemas, ()> {
+    pub fn build_tenants(self) -> Self {
+        self.new_schema("tenant")
+            .prefix("tenant")
+            .new_id_field()
+            .label("Tenant Id")
+            .help("Unique identifier for the tenant")
+            .typ(Type::Input)
+            .input_check([Transformer::Trim], [Validator::Required, Validator::IsId])
+            .build()
+            .new_field("name")
+            .label("Name")
+            .help("Descriptive name for the tenant")
+            .typ(Type::Input)
+            .input_check([Transformer::Trim], [Validator::Required])
+            .build()
+            .new_field("domains")
+            .label("Domains")
+            .help("Domains owned by this tenant, used to validate principal names")
+            .typ(Type::Array)
+            .input_check([Transformer::Trim], [Validator::Required])
+            .build()
+            .new_field("members")
+            .label("Members")
+            .help(concat!(
+                "Principal names that belong to this tenant. Each name must include ",
+                "one of the domains listed above (e.g. 'jdoe@example.org')"
+            ))
+            .typ(Type::Array)
+            .input_check([Transformer::Trim], [])
+            .build()
+            .new_field("quota")
+            .label("Quota")
+            .help("Maximum amount of storage, in bytes, this tenant is allowed to use")
+            .typ(Type::Input)
+            .input_check([], [Validator::MinValue(0.into())])
+            .build()
+            .new_field("limits.accounts")
+            .label("Max accounts")
+            .help("Maximum number of accounts this tenant is allowed to create")
+            .typ(Type::Input)
+            .input_check([], [Validator::MinValue(0.into())])
+            .build()
+            .new_field("limits.groups")
+            .label("Max groups")
+            .help("Maximum number of groups this tenant is allowed to create")
+            .typ(Type::Input)
+            .input_check([], [Validator::MinValue(0.into())])
+            .build()
+            .new_form_section()
+            .title("Tenant")
+            .fields(["name", "domains", "members"])
+            .build()
+            .new_form_section()
+            .title("Limits")
+            .fields(["quota", "limits.accounts", "limits.groups"])
+            .build()
+            .list_title("Tenants")
+            .list_subtitle("Manage tenant quotas and domain-scoped records")
+            .list_fields(["name", "domains"])
+            .build()
+    }
+}